@@ -0,0 +1,43 @@
+use std::fmt;
+
+/// The error type returned by key, signature and address operations.
+#[derive(Debug)]
+pub enum Error {
+    Secp256k1(secp256k1::Error),
+    P256(p256::ecdsa::Error),
+    Base58(crate::base58::Error),
+    /// An operation that isn't meaningful for the curve a signature or key
+    /// was produced over, e.g. recovering a public key from an R1 signature.
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Secp256k1(e) => write!(f, "{}", e),
+            Error::P256(e) => write!(f, "{}", e),
+            Error::Base58(e) => write!(f, "{}", e),
+            Error::Unsupported(what) => write!(f, "unsupported: {}", what),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<secp256k1::Error> for Error {
+    fn from(err: secp256k1::Error) -> Error {
+        Error::Secp256k1(err)
+    }
+}
+
+impl From<p256::ecdsa::Error> for Error {
+    fn from(err: p256::ecdsa::Error) -> Error {
+        Error::P256(err)
+    }
+}
+
+impl From<crate::base58::Error> for Error {
+    fn from(err: crate::base58::Error) -> Error {
+        Error::Base58(err)
+    }
+}
@@ -2,106 +2,337 @@ use std::fmt;
 use std::str::FromStr;
 
 use byteorder::{ByteOrder, LittleEndian};
+use p256::ecdsa::{RecoveryId as R1RecoveryId, Signature as R1Signature};
 use secp256k1::recovery::{RecoverableSignature, RecoveryId};
 
-use crate::{base58, error, hash};
+use crate::{base58, error, hash, PublicKey};
 
-/// An secp256k1 signature.
+/// The leading recovery byte of both the `SIG_K1_`/`SIG_R1_` string encoding
+/// and the compact encoding is `recovery_id + 27 + 4` (the Bitcoin
+/// "compressed" convention), the same for both curves — Antelope/fc and
+/// eosjs both encode it this way regardless of curve.
+const RECOVERY_ID_OFFSET: u8 = 27 + 4;
+
+/// `serialize_compact`/`from_compact` can't tell K1 and R1 signatures apart
+/// by the recovery byte alone (it's the same 31..=34 range for both), so a
+/// leading curve tag is prepended ahead of the recovery byte + 64-byte
+/// signature.
+const K1_CURVE_TAG: u8 = 0;
+const R1_CURVE_TAG: u8 = 1;
+
+/// A signature produced over one of the two elliptic curves Antelope/EOSIO
+/// supports: the original secp256k1 ("K1") curve, and the NIST P-256 ("R1")
+/// curve used by secure-enclave and WebAuthn-backed accounts.
+///
+/// Parsing, displaying, (de)serializing and low-S normalization are fully
+/// supported for both curves. `PublicKey` recovery (see [`Signature::recover`])
+/// is not: it needs a matching `PublicKey::R1` variant (and the key/pubkey
+/// parsing to produce one), which is out of scope here and tracked as its
+/// own follow-up request rather than shipped silently partial.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
-pub struct Signature(RecoverableSignature);
+pub enum Signature {
+    K1(RecoverableSignature),
+    R1(R1Signature, R1RecoveryId),
+}
 
 impl Signature {
     pub fn is_canonical(&self) -> bool {
-        self.0.is_canonical()
+        match self {
+            Signature::K1(sig) => sig.is_canonical(),
+            Signature::R1(sig, _) => sig.normalize_s().is_none(),
+        }
     }
 
-    pub fn to_standard(&self) -> secp256k1::Signature {
-        self.0.to_standard()
+    /// Converts this signature to its low-S form in place. Low-S is
+    /// necessary but not sufficient for [`Signature::is_canonical`]: `nodeos`
+    /// additionally rejects K1 signatures whose compact encoding contains a
+    /// high bit in any of the leading bytes of `r`/`s`, which this does not
+    /// fix. Check `is_canonical()` after calling this if that matters.
+    ///
+    /// `(r, s, recid)` and `(r, n-s, recid^1)` are two encodings of the same
+    /// logical signature, so whenever this actually negates `s` it also
+    /// flips the recovery id's low (y-parity) bit to keep it paired with the
+    /// new `s` — otherwise `recover()` on the "normalized" signature would
+    /// return the wrong public key.
+    pub fn normalize_s(&mut self) {
+        match self {
+            Signature::K1(sig) => {
+                let (recovery_id, bytes) = sig.serialize_compact();
+                let mut standard = sig.to_standard();
+                standard.normalize_s();
+                let normalized = standard.serialize_compact();
+                let recovery_id = if normalized == bytes {
+                    recovery_id
+                } else {
+                    RecoveryId::from_i32(recovery_id.to_i32() ^ 1).expect("flipping the parity bit stays in range")
+                };
+                *sig = RecoverableSignature::from_compact(&normalized, recovery_id)
+                    .expect("normalizing s preserves a valid signature");
+            }
+            Signature::R1(sig, recovery_id) => {
+                if let Some(normalized) = sig.normalize_s() {
+                    *recovery_id = R1RecoveryId::from_byte(recovery_id.to_byte() ^ 1)
+                        .expect("flipping the parity bit stays in range");
+                    *sig = normalized;
+                }
+            }
+        }
     }
 
-    pub fn serialize_compact(&self) -> [u8; 65] {
-        let (recovery_id, sig) = self.0.serialize_compact();
-        let mut data: [u8; 65] = [0u8; 65];
-        data[0] = recovery_id.to_i32() as u8 + 27 + 4;
-        data[1..65].copy_from_slice(&sig[..]);
+    /// Returns the inner `secp256k1::Signature`, or `None` for `R1`
+    /// signatures, which have no secp256k1 representation.
+    pub fn to_standard(&self) -> Option<secp256k1::Signature> {
+        match self {
+            Signature::K1(sig) => Some(sig.to_standard()),
+            Signature::R1(..) => None,
+        }
+    }
+
+    /// Recovers the public key of the account that produced this signature
+    /// over `message_hash`, a 32-byte message digest.
+    ///
+    /// `R1` signatures always return `Error::Unsupported`: `PublicKey` has no
+    /// P-256 representation yet, so there's nothing to recover into. Adding
+    /// an R1 `PublicKey` variant is tracked as a separate follow-up request;
+    /// until it lands, this cannot verify a secure-enclave/WebAuthn account's
+    /// signature, only a K1 one.
+    pub fn recover(&self, message_hash: &[u8; 32]) -> crate::Result<PublicKey> {
+        match self {
+            Signature::K1(sig) => {
+                let secp = secp256k1::Secp256k1::verification_only();
+                let message = secp256k1::Message::from_slice(message_hash)?;
+                Ok(PublicKey::from(secp.recover(&message, sig)?))
+            }
+            Signature::R1(..) => Err(error::Error::Unsupported("recovering an R1 public key")),
+        }
+    }
+
+    /// Hashes `message` with sha256 and recovers the public key of the
+    /// account that produced this signature over the resulting digest. See
+    /// [`Signature::recover`]'s doc for the current `R1` limitation.
+    pub fn recover_from_digest(&self, message: &[u8]) -> crate::Result<PublicKey> {
+        self.recover(&hash::sha256(message).take())
+    }
+
+    pub fn serialize_compact(&self) -> [u8; 66] {
+        let mut data = [0u8; 66];
+        match self {
+            Signature::K1(sig) => {
+                let (recovery_id, bytes) = sig.serialize_compact();
+                data[0] = K1_CURVE_TAG;
+                data[1] = recovery_id.to_i32() as u8 + RECOVERY_ID_OFFSET;
+                data[2..].copy_from_slice(&bytes[..]);
+            }
+            Signature::R1(sig, recovery_id) => {
+                data[0] = R1_CURVE_TAG;
+                data[1] = recovery_id.to_byte() + RECOVERY_ID_OFFSET;
+                data[2..].copy_from_slice(&sig.to_bytes());
+            }
+        }
         data
     }
 
-    pub fn from_compact(data: &[u8; 65]) -> crate::Result<Self> {
-        let id = if data[0] >= 31 {
-            (data[0] - 4 - 27) as i32
-        } else {
-            data[0] as i32
-        };
-        let recv_id = RecoveryId::from_i32(id)?;
-        let recv_sig = RecoverableSignature::from_compact(&data[1..], recv_id)?;
-        Ok(Self(recv_sig))
+    pub fn from_compact(data: &[u8; 66]) -> crate::Result<Self> {
+        if data[1] < RECOVERY_ID_OFFSET {
+            return Err(secp256k1::Error::InvalidSignature.into());
+        }
+        let recovery_id = data[1] - RECOVERY_ID_OFFSET;
+
+        match data[0] {
+            K1_CURVE_TAG => {
+                let recv_id = RecoveryId::from_i32(recovery_id as i32)?;
+                let recv_sig = RecoverableSignature::from_compact(&data[2..66], recv_id)?;
+                Ok(Signature::K1(recv_sig))
+            }
+            R1_CURVE_TAG => {
+                let recv_id = R1RecoveryId::from_byte(recovery_id).ok_or(p256::ecdsa::Error::new())?;
+                let signature = R1Signature::try_from(&data[2..66])?;
+                Ok(Signature::R1(signature, recv_id))
+            }
+            _ => Err(secp256k1::Error::InvalidSignature.into()),
+        }
+    }
+
+    /// Like [`Signature::from_compact`], but errors if the decoded signature
+    /// is not in low-S canonical form instead of silently accepting it.
+    pub fn from_compact_canonical(data: &[u8; 66]) -> crate::Result<Self> {
+        let sig = Self::from_compact(data)?;
+        if !sig.is_canonical() {
+            return Err(secp256k1::Error::InvalidSignature.into());
+        }
+        Ok(sig)
     }
 }
 
 impl From<RecoverableSignature> for Signature {
     fn from(recv_sig: RecoverableSignature) -> Signature {
-        Signature(recv_sig)
+        Signature::K1(recv_sig)
+    }
+}
+
+impl From<(R1Signature, R1RecoveryId)> for Signature {
+    fn from((sig, recovery_id): (R1Signature, R1RecoveryId)) -> Signature {
+        Signature::R1(sig, recovery_id)
+    }
+}
+
+/// Computes the ripemd160-based checksum EOSIO appends to base58-encoded
+/// signatures, salted with the curve tag (`b"K1"` or `b"R1"`) so the two
+/// curves never collide. Shared with the equivalent scheme in the key/pubkey
+/// parsing.
+fn checksum(sig_and_recid: &[u8], curve_tag: &[u8; 2]) -> [u8; 4] {
+    let mut buf = [0u8; 67];
+    buf[..65].copy_from_slice(sig_and_recid);
+    buf[65..67].copy_from_slice(curve_tag);
+    let mut out = [0u8; 4];
+    out.copy_from_slice(&hash::ripemd160(&buf).take()[..4]);
+    out
+}
+
+fn verify_checksum(s_hex: &[u8], curve_tag: &[u8; 2]) -> crate::Result<()> {
+    let expected = LittleEndian::read_u32(&checksum(&s_hex[..65], curve_tag));
+    let actual = LittleEndian::read_u32(&s_hex[65..69]);
+    if expected != actual {
+        return Err(base58::Error::BadChecksum(expected, actual).into());
     }
+    Ok(())
+}
+
+fn encode_with_checksum(sig_and_recid: &[u8; 65], curve_tag: &[u8; 2]) -> String {
+    let mut data = [0u8; 69];
+    data[..65].copy_from_slice(sig_and_recid);
+    data[65..69].copy_from_slice(&checksum(sig_and_recid, curve_tag));
+    base58::encode_slice(&data)
+}
+
+fn from_str_k1(body: &str) -> crate::Result<Signature> {
+    let s_hex = base58::from(body)?;
+    // recovery id length: 1
+    // signature length: 64
+    // checksum length: 4
+    if s_hex.len() != 1 + 64 + 4 {
+        return Err(secp256k1::Error::InvalidSignature.into());
+    }
+
+    verify_checksum(&s_hex, b"K1")?;
+
+    if s_hex[0] < RECOVERY_ID_OFFSET {
+        return Err(secp256k1::Error::InvalidSignature.into());
+    }
+    let recid = RecoveryId::from_i32((s_hex[0] - RECOVERY_ID_OFFSET) as i32)?;
+    let recv_sig = RecoverableSignature::from_compact(&s_hex[1..65], recid)?;
+
+    Ok(Signature::K1(recv_sig))
+}
+
+fn from_str_r1(body: &str) -> crate::Result<Signature> {
+    let s_hex = base58::from(body)?;
+    if s_hex.len() != 1 + 64 + 4 {
+        return Err(secp256k1::Error::InvalidSignature.into());
+    }
+
+    verify_checksum(&s_hex, b"R1")?;
+
+    if s_hex[0] < RECOVERY_ID_OFFSET {
+        return Err(secp256k1::Error::InvalidSignature.into());
+    }
+    let recovery_id = R1RecoveryId::from_byte(s_hex[0] - RECOVERY_ID_OFFSET).ok_or(p256::ecdsa::Error::new())?;
+    let signature = R1Signature::try_from(&s_hex[1..65])?;
+
+    Ok(Signature::R1(signature, recovery_id))
 }
 
 impl FromStr for Signature {
     type Err = error::Error;
 
     fn from_str(s: &str) -> crate::Result<Signature> {
-        if !s.starts_with("SIG_K1_") {
-            return Err(secp256k1::Error::InvalidSignature.into());
+        if let Some(body) = s.strip_prefix("SIG_K1_") {
+            from_str_k1(body)
+        } else if let Some(body) = s.strip_prefix("SIG_R1_") {
+            from_str_r1(body)
+        } else {
+            Err(secp256k1::Error::InvalidSignature.into())
         }
+    }
+}
 
-        let s_hex = base58::from(&s[7..])?;
-        // recovery id length: 1
-        // signature length: 64
-        // checksum length: 4
-        if s_hex.len() != 1 + 64 + 4 {
+impl Signature {
+    /// Like [`Signature::from_str`], but errors if the decoded signature is
+    /// not in low-S canonical form instead of silently accepting it.
+    pub fn from_str_canonical(s: &str) -> crate::Result<Self> {
+        let sig = Self::from_str(s)?;
+        if !sig.is_canonical() {
             return Err(secp256k1::Error::InvalidSignature.into());
         }
-
-        let recid = secp256k1::recovery::RecoveryId::from_i32((s_hex[0] - 4 - 27) as i32)?;
-        let data = &s_hex[1..65];
-
-        // Verify checksum
-        let mut checksum_data = [0u8; 67];
-        checksum_data[..65].copy_from_slice(&s_hex[..65]);
-        checksum_data[65..67].copy_from_slice(b"K1");
-        let expected = LittleEndian::read_u32(&hash::ripemd160(&checksum_data)[..4]);
-        let actual = LittleEndian::read_u32(&s_hex[65..69]);
-        if expected != actual {
-            return Err(base58::Error::BadChecksum(expected, actual).into());
-        }
-
-        let rec_sig = secp256k1::recovery::RecoverableSignature::from_compact(&data, recid)?;
-
-        Ok(Signature(rec_sig))
+        Ok(sig)
     }
 }
 
 impl fmt::Display for Signature {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (recovery_id, sig) = self.0.serialize_compact();
-
         // See https://github.com/EOSIO/fc/blob/f4755d330faf9d2342d646a93f9a27bf68ca759e/src/crypto/elliptic_impl_priv.cpp
-        let mut checksum_data: [u8; 67] = [0u8; 67];
-        checksum_data[0] = recovery_id.to_i32() as u8 + 27 + 4;
-        checksum_data[1..65].copy_from_slice(&sig[..]);
-        checksum_data[65..67].copy_from_slice(b"K1");
-
-        // Compute ripemd160 checksum
-        let checksum_h160 = hash::ripemd160(&checksum_data);
-        let checksum = &checksum_h160.take()[..4];
+        match self {
+            Signature::K1(sig) => {
+                let (recovery_id, bytes) = sig.serialize_compact();
+                let mut sig_and_recid = [0u8; 65];
+                sig_and_recid[0] = recovery_id.to_i32() as u8 + RECOVERY_ID_OFFSET;
+                sig_and_recid[1..].copy_from_slice(&bytes[..]);
+                write!(f, "SIG_K1_{}", encode_with_checksum(&sig_and_recid, b"K1"))
+            }
+            Signature::R1(sig, recovery_id) => {
+                let mut sig_and_recid = [0u8; 65];
+                sig_and_recid[0] = recovery_id.to_byte() + RECOVERY_ID_OFFSET;
+                sig_and_recid[1..].copy_from_slice(&sig.to_bytes());
+                write!(f, "SIG_R1_{}", encode_with_checksum(&sig_and_recid, b"R1"))
+            }
+        }
+    }
+}
 
-        // Signature slice
-        let mut sig_slice: [u8; 69] = [0u8; 69];
-        sig_slice[..65].copy_from_slice(&checksum_data[..65]);
-        sig_slice[65..69].copy_from_slice(&checksum[..]);
+#[cfg(feature = "serde")]
+impl serde::Serialize for Signature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            serializer.serialize_bytes(&self.serialize_compact())
+        }
+    }
+}
 
-        write!(f, "SIG_K1_{}", base58::encode_slice(&sig_slice))?;
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Signature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error as _;
+        use serde::Deserialize as _;
 
-        Ok(())
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            Signature::from_str(&s).map_err(D::Error::custom)
+        } else {
+            struct CompactVisitor;
+            impl<'de> serde::de::Visitor<'de> for CompactVisitor {
+                type Value = Signature;
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "a 66-byte compact signature")
+                }
+                fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Signature, E> {
+                    if v.len() != 66 {
+                        return Err(E::invalid_length(v.len(), &self));
+                    }
+                    let mut data = [0u8; 66];
+                    data.copy_from_slice(v);
+                    Signature::from_compact(&data).map_err(E::custom)
+                }
+            }
+            deserializer.deserialize_bytes(CompactVisitor)
+        }
     }
 }
 
@@ -109,7 +340,35 @@ impl fmt::Display for Signature {
 mod test {
     use std::str::FromStr;
 
-    use super::Signature;
+    use super::{PublicKey, Signature};
+
+    #[test]
+    fn sig_recover_should_return_the_signing_pubkey() {
+        let secp = secp256k1::Secp256k1::new();
+        let secret_key = secp256k1::SecretKey::from_slice(&[0x11; 32]).unwrap();
+        let message_hash = [0x22; 32];
+        let message = secp256k1::Message::from_slice(&message_hash).unwrap();
+
+        let recoverable_sig = secp.sign_recoverable(&message, &secret_key);
+        let sig = Signature::from(recoverable_sig);
+
+        let expected = PublicKey::from(secp256k1::PublicKey::from_secret_key(&secp, &secret_key));
+        assert_eq!(sig.recover(&message_hash).unwrap(), expected);
+    }
+
+    #[test]
+    fn sig_recover_from_digest_should_match_recover_of_the_sha256_hash() {
+        let secp = secp256k1::Secp256k1::new();
+        let secret_key = secp256k1::SecretKey::from_slice(&[0x11; 32]).unwrap();
+        let message = b"howdy";
+        let message_hash = crate::hash::sha256(message).take();
+        let sig_message = secp256k1::Message::from_slice(&message_hash).unwrap();
+
+        let recoverable_sig = secp.sign_recoverable(&sig_message, &secret_key);
+        let sig = Signature::from(recoverable_sig);
+
+        assert_eq!(sig.recover_from_digest(message).unwrap(), sig.recover(&message_hash).unwrap());
+    }
 
     #[test]
     fn sig_from_str_should_work() {
@@ -125,4 +384,156 @@ mod test {
         let sig = Signature::from_str(sig_str);
         assert!(sig.is_err());
     }
+
+    #[test]
+    fn sig_from_str_should_error_on_invalid_r1_body() {
+        let sig_str = "SIG_R1_not_a_valid_signature";
+        let sig = Signature::from_str(sig_str);
+        assert!(sig.is_err());
+    }
+
+    #[test]
+    fn sig_should_roundtrip_an_r1_signature_through_display_and_from_str() {
+        let signature = p256::ecdsa::Signature::try_from(&[0x11u8; 64][..]).unwrap();
+        let recovery_id = p256::ecdsa::RecoveryId::from_byte(1).unwrap();
+        let sig = Signature::from((signature, recovery_id));
+
+        let sig_str = sig.to_string();
+        assert!(sig_str.starts_with("SIG_R1_"));
+
+        let parsed = Signature::from_str(&sig_str).unwrap();
+        assert_eq!(parsed, sig);
+    }
+
+    #[test]
+    fn sig_should_roundtrip_through_compact() {
+        let signature = p256::ecdsa::Signature::try_from(&[0x11u8; 64][..]).unwrap();
+        let recovery_id = p256::ecdsa::RecoveryId::from_byte(1).unwrap();
+        let sig = Signature::from((signature, recovery_id));
+
+        let compact = sig.serialize_compact();
+        let parsed = Signature::from_compact(&compact).unwrap();
+        assert_eq!(parsed, sig);
+    }
+
+    #[test]
+    fn sig_from_str_canonical_should_accept_canonical_sig() {
+        let sig_str = "SIG_K1_KBJgSuRYtHZcrWThugi4ygFabto756zuQQo8XeEpyRtBXLb9kbJtNW3xDcS14Rc14E8iHqLrdx46nenG5T7R4426Bspyzk";
+        let sig = Signature::from_str_canonical(sig_str);
+        assert!(sig.is_ok());
+    }
+
+    // The secp256k1 curve order n, used to negate a canonical (low-S) test
+    // vector's `s` into a high-S one: `s' = n - s`.
+    const SECP256K1_ORDER: [u8; 32] = [
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0xBA, 0xAE,
+        0xDC, 0xE6, 0xAF, 0x48, 0xA0, 0x3B, 0xBF, 0xD2, 0x5E, 0x8C, 0xD0, 0x36, 0x41, 0x41,
+    ];
+
+    fn negate_mod_order(s: &[u8; 32]) -> [u8; 32] {
+        let mut result = [0u8; 32];
+        let mut borrow = 0i16;
+        for i in (0..32).rev() {
+            let diff = SECP256K1_ORDER[i] as i16 - s[i] as i16 - borrow;
+            if diff < 0 {
+                result[i] = (diff + 256) as u8;
+                borrow = 1;
+            } else {
+                result[i] = diff as u8;
+                borrow = 0;
+            }
+        }
+        result
+    }
+
+    fn non_canonical_k1_compact() -> [u8; 66] {
+        let sig_str = "SIG_K1_KBJgSuRYtHZcrWThugi4ygFabto756zuQQo8XeEpyRtBXLb9kbJtNW3xDcS14Rc14E8iHqLrdx46nenG5T7R4426Bspyzk";
+        let canonical = Signature::from_str(sig_str).unwrap();
+        let mut compact = canonical.serialize_compact();
+        let mut s = [0u8; 32];
+        s.copy_from_slice(&compact[34..66]);
+        compact[34..66].copy_from_slice(&negate_mod_order(&s));
+        compact
+    }
+
+    #[test]
+    fn sig_normalize_s_should_fix_a_high_s_k1_signature() {
+        let compact = non_canonical_k1_compact();
+        let mut sig = Signature::from_compact(&compact).unwrap();
+        assert!(!sig.is_canonical());
+
+        sig.normalize_s();
+        assert!(sig.is_canonical());
+    }
+
+    #[test]
+    fn sig_normalize_s_should_still_recover_the_signing_pubkey_for_a_high_s_signature() {
+        let secp = secp256k1::Secp256k1::new();
+        let secret_key = secp256k1::SecretKey::from_slice(&[0x33; 32]).unwrap();
+        let message_hash = [0x44; 32];
+        let message = secp256k1::Message::from_slice(&message_hash).unwrap();
+        let expected = PublicKey::from(secp256k1::PublicKey::from_secret_key(&secp, &secret_key));
+
+        let canonical_sig = secp.sign_recoverable(&message, &secret_key);
+
+        // Force the signature into its non-canonical (high-S) encoding.
+        // `(r, s, recid)` and `(r, n-s, recid^1)` are two valid encodings of
+        // the same logical signature, so negating `s` here also requires
+        // flipping `recid`'s low bit to keep them paired correctly.
+        let (recovery_id, bytes) = canonical_sig.serialize_compact();
+        let mut s = [0u8; 32];
+        s.copy_from_slice(&bytes[32..]);
+        let mut forced = bytes;
+        forced[32..].copy_from_slice(&negate_mod_order(&s));
+        let flipped_recid = secp256k1::recovery::RecoveryId::from_i32(recovery_id.to_i32() ^ 1).unwrap();
+        let non_canonical = secp256k1::recovery::RecoverableSignature::from_compact(&forced, flipped_recid).unwrap();
+
+        let mut sig = Signature::from(non_canonical);
+        assert!(!sig.is_canonical());
+        assert_eq!(sig.recover(&message_hash).unwrap(), expected);
+
+        sig.normalize_s();
+        assert!(sig.is_canonical());
+        assert_eq!(sig.recover(&message_hash).unwrap(), expected);
+    }
+
+    #[test]
+    fn sig_from_compact_canonical_should_reject_non_canonical_sig() {
+        let compact = non_canonical_k1_compact();
+        assert!(Signature::from_compact_canonical(&compact).is_err());
+    }
+
+    #[test]
+    fn sig_from_str_canonical_should_reject_non_canonical_sig() {
+        let compact = non_canonical_k1_compact();
+        let non_canonical = Signature::from_compact(&compact).unwrap();
+        assert!(!non_canonical.is_canonical());
+
+        let sig_str = non_canonical.to_string();
+        assert!(Signature::from_str_canonical(&sig_str).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn sig_should_serialize_and_deserialize_to_same_string() {
+        let sig_str = "SIG_K1_KBJgSuRYtHZcrWThugi4ygFabto756zuQQo8XeEpyRtBXLb9kbJtNW3xDcS14Rc14E8iHqLrdx46nenG5T7R4426Bspyzk";
+        let sig = Signature::from_str(sig_str).unwrap();
+        let json = serde_json::to_string(&sig).unwrap();
+        assert_eq!(json, format!("\"{}\"", sig_str));
+        let deserialized: Signature = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, sig);
+    }
+
+    /// `bincode` is not human-readable, so this round-trips through the
+    /// `serialize_bytes`/`from_compact` branch that the JSON test above
+    /// never touches.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn sig_should_serialize_and_deserialize_through_a_binary_format() {
+        let sig_str = "SIG_K1_KBJgSuRYtHZcrWThugi4ygFabto756zuQQo8XeEpyRtBXLb9kbJtNW3xDcS14Rc14E8iHqLrdx46nenG5T7R4426Bspyzk";
+        let sig = Signature::from_str(sig_str).unwrap();
+        let bytes = bincode::serialize(&sig).unwrap();
+        let deserialized: Signature = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(deserialized, sig);
+    }
 }